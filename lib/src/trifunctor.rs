@@ -0,0 +1,98 @@
+use crate::Trilift;
+
+/// A `Functor` over three arguments.
+pub trait Trifunctor<A, B, C, D, E, F>: Trilift<A, B, C, D, E, F> {
+    fn trimap<L, M, R>(self, left: L, middle: M, right: R) -> <Self as Trilift<A, B, C, D, E, F>>::Target
+    where
+        L: Fn(A) -> D,
+        M: Fn(B) -> E,
+        R: Fn(C) -> F;
+}
+
+pub trait TrifunctorLeft<A, B, C, D>: Trifunctor<A, B, C, D, B, C> {
+    fn lmap<F>(self, f: F) -> <Self as Trilift<A, B, C, D, B, C>>::Target
+    where
+        F: Fn(A) -> D;
+}
+
+impl<A, B, C, D> TrifunctorLeft<A, B, C, D> for A
+where
+    A: Trifunctor<A, B, C, D, B, C>,
+{
+    fn lmap<F>(self, f: F) -> <Self as Trilift<A, B, C, D, B, C>>::Target
+    where
+        F: Fn(A) -> D,
+    {
+        self.trimap(f, |b| b, |c| c)
+    }
+}
+
+pub trait TrifunctorMiddle<A, B, C, E>: Trifunctor<A, B, C, A, E, C> {
+    fn mmap<F>(self, f: F) -> <Self as Trilift<A, B, C, A, E, C>>::Target
+    where
+        F: Fn(B) -> E;
+}
+
+impl<A, B, C, E> TrifunctorMiddle<A, B, C, E> for A
+where
+    A: Trifunctor<A, B, C, A, E, C>,
+{
+    fn mmap<F>(self, f: F) -> <Self as Trilift<A, B, C, A, E, C>>::Target
+    where
+        F: Fn(B) -> E,
+    {
+        self.trimap(|a| a, f, |c| c)
+    }
+}
+
+pub trait TrifunctorRight<A, B, C, F>: Trifunctor<A, B, C, A, B, F> {
+    fn rmap<G>(self, g: G) -> <Self as Trilift<A, B, C, A, B, F>>::Target
+    where
+        G: Fn(C) -> F;
+}
+
+impl<A, B, C, F> TrifunctorRight<A, B, C, F> for A
+where
+    A: Trifunctor<A, B, C, A, B, F>,
+{
+    fn rmap<G>(self, g: G) -> <Self as Trilift<A, B, C, A, B, F>>::Target
+    where
+        G: Fn(C) -> F,
+    {
+        self.trimap(|a| a, |b| b, g)
+    }
+}
+
+impl<A, B, C, D, E, F> Trilift<A, B, C, D, E, F> for (A, B, C) {
+    type Target = (D, E, F);
+}
+
+impl<A, B, C, D, E, F> Trifunctor<A, B, C, D, E, F> for (A, B, C) {
+    fn trimap<L, M, R>(self, left: L, middle: M, right: R) -> <Self as Trilift<A, B, C, D, E, F>>::Target
+    where
+        L: Fn(A) -> D,
+        M: Fn(B) -> E,
+        R: Fn(C) -> F,
+    {
+        (left(self.0), middle(self.1), right(self.2))
+    }
+}
+
+impl<A, B, C, D, E, F> Trilift<A, B, C, D, E, F> for Result<A, Result<B, C>> {
+    type Target = Result<D, Result<E, F>>;
+}
+
+impl<A, B, C, D, E, F> Trifunctor<A, B, C, D, E, F> for Result<A, Result<B, C>> {
+    fn trimap<L, M, R>(self, left: L, middle: M, right: R) -> <Self as Trilift<A, B, C, D, E, F>>::Target
+    where
+        L: Fn(A) -> D,
+        M: Fn(B) -> E,
+        R: Fn(C) -> F,
+    {
+        match self {
+            Ok(a) => Ok(left(a)),
+            Err(Ok(b)) => Err(Ok(middle(b))),
+            Err(Err(c)) => Err(Err(right(c))),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Picks the result type of a [`crate::Trifunctor::trimap`] call for a given
+/// combination of input and output type parameters, the three-argument
+/// counterpart to [`crate::Bilift`].
+pub trait Trilift<A, B, C, D, E, F> {
+    type Target;
+}
@@ -0,0 +1,179 @@
+use crate::{Functor, Pure};
+
+/// `Apply` lets you combine two functorial values by applying a function
+/// wrapped in `F<_>` to a value wrapped in `F<_>`.
+///
+/// Where `Functor::fmap` only ever has a single plain function to apply,
+/// `apply` lets the function itself carry the same context as the value it
+/// is applied to. That is what makes validation-style combination (collect
+/// both sides instead of stopping at the first one) and N-ary lifting
+/// possible, neither of which `fmap` alone can express.
+pub trait Apply<'a, A>: Functor<'a, A>
+where
+    A: 'a,
+{
+    /// Apply a wrapped function to this wrapped value.
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        Self: Sized,
+        B: 'a,
+        F: Fn(A) -> B + 'a;
+
+    /// Combine two wrapped values with a plain binary function.
+    fn map2<B, C, G>(self, other: Self::Target<B>, f: G) -> Self::Target<C>
+    where
+        Self: Sized,
+        A: Clone,
+        B: 'a,
+        C: 'a,
+        G: Fn(A, B) -> C + 'a,
+        Self::Target<B>: Apply<
+            'a,
+            B,
+            Target<C> = Self::Target<C>,
+            Target<Box<dyn Fn(B) -> C + 'a>> = Self::Target<Box<dyn Fn(B) -> C + 'a>>,
+        >,
+    {
+        let f = std::rc::Rc::new(f);
+        let lifted: Self::Target<Box<dyn Fn(B) -> C + 'a>> = self.fmap(move |a| {
+            let f = f.clone();
+            Box::new(move |b: B| f(a.clone(), b)) as Box<dyn Fn(B) -> C + 'a>
+        });
+        other.apply::<C, Box<dyn Fn(B) -> C + 'a>>(lifted)
+    }
+
+    /// Combine two wrapped values into a wrapped pair.
+    fn product<B>(self, other: Self::Target<B>) -> Self::Target<(A, B)>
+    where
+        Self: Sized,
+        A: Clone,
+        B: 'a,
+        Self::Target<B>: Apply<
+            'a,
+            B,
+            Target<(A, B)> = Self::Target<(A, B)>,
+            Target<Box<dyn Fn(B) -> (A, B) + 'a>> = Self::Target<Box<dyn Fn(B) -> (A, B) + 'a>>,
+        >,
+    {
+        self.map2(other, |a, b| (a, b))
+    }
+}
+
+/// An `Applicative` can both lift a plain value in ([`Pure::pure`]) and
+/// combine wrapped values ([`Apply::apply`]).
+pub trait Applicative<'a, A>: Pure<A> + Apply<'a, A> where A: 'a {}
+
+impl<'a, A, T> Applicative<'a, A> for T
+where
+    T: Pure<A> + Apply<'a, A>,
+    A: 'a,
+{
+}
+
+impl<'a, A: 'a> Apply<'a, A> for Option<A> {
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+    {
+        match self {
+            Some(a) => f.map(|f| f(a)),
+            None => None,
+        }
+    }
+}
+
+impl<'a, A: 'a, E> Apply<'a, A> for Result<A, E> {
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+    {
+        match self {
+            Ok(a) => f.map(|f| f(a)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<'a, A: 'a + Clone> Apply<'a, A> for Vec<A> {
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+    {
+        let mut out = Vec::with_capacity(f.len() * self.len());
+        for g in &f {
+            for a in &self {
+                out.push(g(a.clone()));
+            }
+        }
+        out
+    }
+}
+
+impl<'a, A: 'a + Clone> Apply<'a, A> for std::collections::VecDeque<A> {
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(A) -> B + 'a,
+    {
+        let mut out = std::collections::VecDeque::with_capacity(f.len() * self.len());
+        for g in &f {
+            for a in &self {
+                out.push_back(g(a.clone()));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Apply;
+
+    #[test]
+    fn option_apply_both_some() {
+        let a = Some(2);
+        let f = Some(|x: i32| x + 3);
+        assert_eq!(a.apply(f), Some(5));
+    }
+
+    #[test]
+    fn option_apply_short_circuits() {
+        let a: Option<i32> = None;
+        let f = Some(|x: i32| x + 3);
+        assert_eq!(a.apply(f), None);
+    }
+
+    #[test]
+    fn result_apply_short_circuits_on_first_err() {
+        let a: Result<i32, &str> = Err("boom");
+        let f: Result<_, &str> = Ok(|x: i32| x + 3);
+        assert_eq!(a.apply(f), Err("boom"));
+    }
+
+    #[test]
+    fn vec_apply_is_cartesian_row_major() {
+        let a = vec![1, 2];
+        let f: Vec<Box<dyn Fn(i32) -> i32>> = vec![Box::new(|x| x + 1), Box::new(|x| x * 10)];
+        assert_eq!(a.apply(f), vec![2, 3, 10, 20]);
+    }
+
+    #[test]
+    fn vec_product_is_cartesian_row_major() {
+        let a = vec![1, 2];
+        let b = vec!["x", "y"];
+        assert_eq!(
+            a.product(b),
+            vec![(1, "x"), (1, "y"), (2, "x"), (2, "y")]
+        );
+    }
+
+    #[test]
+    fn option_map2() {
+        let a = Some(2);
+        let b = Some(3);
+        assert_eq!(a.map2(b, |x, y| x + y), Some(5));
+    }
+}
@@ -43,6 +43,10 @@ where
 
     /// Turn the functor into an iterator.
     ///
+    /// Kept as a defaulted wrapper for backward compatibility; prefer
+    /// [`FunctorIntoIter::functor_into_iter`], which iterates the container
+    /// natively instead of collecting into an intermediate `Vec`.
+    ///
     /// ```
     /// # use higher::Functor;
     /// let my_functor = vec![1, 2, 3];
@@ -70,16 +74,71 @@ where
 
     fn funzip<L: 'a, R: 'a, FL, FR, Z>(self, f: Z) -> (FL, FR)
     where
-        A: 'static,
-        Self: Sized + Functor<'a, A, Target<L> = FL> + Functor<'a, A, Target<R> = FR>,
+        Self: Sized + FunctorIntoIter<A> + Functor<'a, A, Target<L> = FL> + Functor<'a, A, Target<R> = FR>,
         FL: Functor<'a, L, Target<A> = Self> + Default + Extend<L>,
         FR: Functor<'a, R, Target<A> = Self> + Default + Extend<R>,
         Z: Fn(A) -> (L, R) + 'a,
     {
-        self.f_into_iter().map(|a| f(a)).unzip()
+        self.functor_into_iter().map(f).unzip()
+    }
+}
+
+/// Turns a functor into its natural iterator without any intermediate
+/// allocation, unlike [`Functor::f_into_iter`] which has to stash every
+/// element into a shared `Vec` via a side-effecting `fmap`.
+pub trait FunctorIntoIter<A> {
+    type IntoIter: Iterator<Item = A>;
+
+    /// Turn the functor into its native iterator.
+    fn functor_into_iter(self) -> Self::IntoIter;
+}
+
+impl<A> FunctorIntoIter<A> for Option<A> {
+    type IntoIter = std::option::IntoIter<A>;
+
+    fn functor_into_iter(self) -> Self::IntoIter {
+        self.into_iter()
     }
 }
 
+impl<A, E> FunctorIntoIter<A> for Result<A, E> {
+    type IntoIter = std::result::IntoIter<A>;
+
+    fn functor_into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<A, const N: usize> FunctorIntoIter<A> for [A; N] {
+    type IntoIter = std::array::IntoIter<A, N>;
+
+    fn functor_into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+macro_rules! impl_functor_into_iter {
+    ($ty:ty, $iter:ty) => {
+        impl<A> FunctorIntoIter<A> for $ty {
+            type IntoIter = $iter;
+
+            fn functor_into_iter(self) -> Self::IntoIter {
+                self.into_iter()
+            }
+        }
+    };
+}
+
+impl_functor_into_iter!(Vec<A>, std::vec::IntoIter<A>);
+impl_functor_into_iter!(
+    std::collections::VecDeque<A>,
+    std::collections::vec_deque::IntoIter<A>
+);
+impl_functor_into_iter!(
+    std::collections::LinkedList<A>,
+    std::collections::linked_list::IntoIter<A>
+);
+
 impl<'a, A: 'a> Functor<'a, A> for Option<A> {
     type Target<T> = Option<T> where T: 'a;
 
@@ -154,9 +213,40 @@ impl<'a, A: 'a> Functor<'a, A> for std::collections::LinkedList<A> {
     impl_fmap_from_iter!();
 }
 
+impl<'a, K, V: 'a, S> Functor<'a, V> for std::collections::HashMap<K, V, S>
+where
+    K: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Default,
+{
+    type Target<T> = std::collections::HashMap<K, T, S> where T: 'a;
+
+    fn fmap<B, F>(self, f: F) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(V) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<'a, K, V: 'a> Functor<'a, V> for std::collections::BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Target<T> = std::collections::BTreeMap<K, T> where T: 'a;
+
+    fn fmap<B, F>(self, f: F) -> Self::Target<B>
+    where
+        B: 'a,
+        F: Fn(V) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::Functor;
+    use crate::{Functor, FunctorIntoIter};
 
     #[test]
     fn option_functor() {
@@ -193,6 +283,26 @@ mod test {
         assert_eq!(b, vec![2usize, 4usize, 6usize]);
     }
 
+    #[test]
+    fn hashmap_functor_keeps_keys() {
+        let mut a = std::collections::HashMap::new();
+        a.insert("one", 1);
+        a.insert("two", 2);
+        let b = a.fmap(|x| x * 10);
+        assert_eq!(b.get("one"), Some(&10));
+        assert_eq!(b.get("two"), Some(&20));
+    }
+
+    #[test]
+    fn btreemap_functor_keeps_keys() {
+        let mut a = std::collections::BTreeMap::new();
+        a.insert(1, "one");
+        a.insert(2, "two");
+        let b = a.fmap(|x| x.to_uppercase());
+        assert_eq!(b.get(&1), Some(&"ONE".to_string()));
+        assert_eq!(b.get(&2), Some(&"TWO".to_string()));
+    }
+
     #[test]
     fn unzip() {
         let a = vec![(1usize, 2i32), (2usize, 4i32), (3usize, 6i32)];
@@ -200,4 +310,11 @@ mod test {
         assert_eq!(l, vec![1usize, 2usize, 3usize]);
         assert_eq!(r, vec![2i32, 4i32, 6i32]);
     }
+
+    #[test]
+    fn functor_into_iter_preserves_order() {
+        let a = vec![1, 2, 3];
+        let v: Vec<i32> = a.functor_into_iter().collect();
+        assert_eq!(v, vec![1, 2, 3]);
+    }
 }